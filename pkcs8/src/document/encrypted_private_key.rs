@@ -0,0 +1,106 @@
+//! PKCS#8 encrypted private key document.
+
+use crate::{private_key_info::encrypted::EncryptedPrivateKeyInfo, Error, Result};
+use alloc::vec::Vec;
+use core::{convert::TryFrom, fmt};
+use der::Decodable;
+
+#[cfg(feature = "pem")]
+use der::pem::{self, LineEnding};
+
+#[cfg(feature = "encryption")]
+use crate::document::private_key::PrivateKeyDocument;
+
+#[cfg(feature = "std")]
+use std::{fs, path::Path};
+
+/// PKCS#8 `EncryptedPrivateKeyInfo` document.
+///
+/// This type provides heap-backed storage for [`EncryptedPrivateKeyInfo`] encoded as ASN.1 DER.
+#[derive(Clone)]
+pub struct EncryptedPrivateKeyDocument(Vec<u8>);
+
+impl EncryptedPrivateKeyDocument {
+    /// Parse the [`EncryptedPrivateKeyInfo`] contained in this document.
+    pub fn encrypted_private_key_info(&self) -> EncryptedPrivateKeyInfo<'_> {
+        EncryptedPrivateKeyInfo::from_der(self.0.as_slice())
+            .expect("malformed EncryptedPrivateKeyDocument")
+    }
+
+    /// Get the DER-encoded bytes of this document.
+    pub fn as_der(&self) -> &[u8] {
+        self.0.as_slice()
+    }
+
+    /// Attempt to decrypt this encrypted private key using the given password.
+    #[cfg(feature = "encryption")]
+    pub fn decrypt(&self, password: impl AsRef<[u8]>) -> Result<PrivateKeyDocument> {
+        self.encrypted_private_key_info().decrypt(password)
+    }
+
+    /// Parse [`EncryptedPrivateKeyDocument`] from ASN.1 DER-encoded bytes.
+    pub fn from_der(bytes: &[u8]) -> Result<Self> {
+        EncryptedPrivateKeyInfo::from_der(bytes)?;
+        Ok(Self(bytes.to_vec()))
+    }
+
+    /// Parse [`EncryptedPrivateKeyDocument`] from PEM-encoded PKCS#8.
+    #[cfg(feature = "pem")]
+    pub fn from_pem(s: &str) -> Result<Self> {
+        let (label, der_bytes) = pem::decode_vec(s.as_bytes())?;
+
+        if label != crate::pem::ENCRYPTED_PRIVATE_KEY_TYPE_LABEL {
+            return Err(Error::KeyMalformed);
+        }
+
+        Self::from_der(&der_bytes)
+    }
+
+    /// Serialize this document as PEM-encoded PKCS#8 using the given line ending.
+    #[cfg(feature = "pem")]
+    pub fn to_pem(&self, line_ending: LineEnding) -> Result<alloc::string::String> {
+        Ok(pem::encode_string(
+            crate::pem::ENCRYPTED_PRIVATE_KEY_TYPE_LABEL,
+            line_ending,
+            self.as_der(),
+        )?)
+    }
+
+    /// Load an encrypted private key from an ASCII PEM-encoded file on the local filesystem.
+    #[cfg(feature = "pem")]
+    #[cfg(feature = "std")]
+    pub fn read_pem_file(path: impl AsRef<Path>) -> Result<Self> {
+        Self::from_pem(&fs::read_to_string(path).map_err(|_| Error::KeyMalformed)?)
+    }
+
+    /// Load an encrypted private key from a DER-encoded file on the local filesystem.
+    #[cfg(feature = "std")]
+    pub fn read_der_file(path: impl AsRef<Path>) -> Result<Self> {
+        Self::from_der(&fs::read(path).map_err(|_| Error::KeyMalformed)?)
+    }
+}
+
+impl TryFrom<&[u8]> for EncryptedPrivateKeyDocument {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self> {
+        Self::from_der(bytes)
+    }
+}
+
+impl<'a> TryFrom<EncryptedPrivateKeyInfo<'a>> for EncryptedPrivateKeyDocument {
+    type Error = Error;
+
+    fn try_from(key_info: EncryptedPrivateKeyInfo<'a>) -> Result<Self> {
+        use der::Encodable;
+        Ok(Self(key_info.to_vec()?))
+    }
+}
+
+impl fmt::Debug for EncryptedPrivateKeyDocument {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("EncryptedPrivateKeyDocument")
+            .field(&self.encrypted_private_key_info())
+            .finish()
+    }
+}
@@ -0,0 +1,7 @@
+//! Heap-backed storage types for PKCS#8 documents.
+
+pub(crate) mod private_key;
+pub(crate) mod public_key;
+
+#[cfg(feature = "pkcs5")]
+pub(crate) mod encrypted_private_key;
@@ -0,0 +1,201 @@
+//! PKCS#8 private key document.
+
+use crate::{Error, PrivateKeyInfo, Result};
+use alloc::vec::Vec;
+use core::{convert::TryFrom, fmt};
+use der::Decodable;
+
+#[cfg(feature = "pem")]
+use der::pem::{self, LineEnding};
+
+#[cfg(feature = "std")]
+use std::{fs, path::Path};
+
+#[cfg(feature = "encryption")]
+use {
+    crate::{document::encrypted_private_key::EncryptedPrivateKeyDocument, private_key_info},
+    rand_core::{CryptoRng, RngCore},
+};
+
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+/// PKCS#8 `PrivateKeyInfo` document.
+///
+/// This type provides heap-backed storage for [`PrivateKeyInfo`] encoded as ASN.1 DER.
+///
+/// With the `zeroize` feature enabled, the DER-encoded private key is wiped
+/// from memory when this type is dropped.
+#[derive(Clone)]
+pub struct PrivateKeyDocument(Vec<u8>);
+
+#[cfg(feature = "zeroize")]
+impl Drop for PrivateKeyDocument {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl PrivateKeyDocument {
+    /// Parse the [`PrivateKeyInfo`] contained in this [`PrivateKeyDocument`].
+    pub fn private_key_info(&self) -> PrivateKeyInfo<'_> {
+        PrivateKeyInfo::from_der(self.0.as_slice()).expect("malformed PrivateKeyDocument")
+    }
+
+    /// Get the DER-encoded bytes of this document.
+    pub fn as_der(&self) -> &[u8] {
+        self.0.as_slice()
+    }
+
+    /// Parse [`PrivateKeyDocument`] from ASN.1 DER-encoded bytes.
+    pub fn from_der(bytes: &[u8]) -> Result<Self> {
+        PrivateKeyInfo::from_der(bytes)?;
+        Ok(Self(bytes.to_vec()))
+    }
+
+    /// Parse [`PrivateKeyDocument`] from PEM-encoded PKCS#8.
+    #[cfg(feature = "pem")]
+    pub fn from_pem(s: &str) -> Result<Self> {
+        let (label, der_bytes) = pem::decode_vec(s.as_bytes())?;
+
+        if label != crate::pem::PRIVATE_KEY_TYPE_LABEL {
+            return Err(Error::KeyMalformed);
+        }
+
+        Self::from_der(&der_bytes)
+    }
+
+    /// Serialize this [`PrivateKeyDocument`] as PEM-encoded PKCS#8 using the given line ending.
+    #[cfg(feature = "pem")]
+    pub fn to_pem(&self, line_ending: LineEnding) -> Result<alloc::string::String> {
+        Ok(pem::encode_string(
+            crate::pem::PRIVATE_KEY_TYPE_LABEL,
+            line_ending,
+            self.as_der(),
+        )?)
+    }
+
+    /// Load [`PrivateKeyDocument`] from an ASCII PEM-encoded file on the local filesystem.
+    #[cfg(feature = "pem")]
+    #[cfg(feature = "std")]
+    pub fn read_pem_file(path: impl AsRef<Path>) -> Result<Self> {
+        Self::from_pem(&fs::read_to_string(path).map_err(|_| Error::KeyMalformed)?)
+    }
+
+    /// Load [`PrivateKeyDocument`] from a DER-encoded file on the local filesystem.
+    #[cfg(feature = "std")]
+    pub fn read_der_file(path: impl AsRef<Path>) -> Result<Self> {
+        Self::from_der(&fs::read(path).map_err(|_| Error::KeyMalformed)?)
+    }
+
+    /// Write ASCII PEM-encoded PKCS#8 private key to the given path.
+    #[cfg(feature = "pem")]
+    #[cfg(feature = "std")]
+    pub fn write_pem_file(&self, path: impl AsRef<Path>, line_ending: LineEnding) -> Result<()> {
+        fs::write(path, self.to_pem(line_ending)?.as_bytes()).map_err(|_| Error::KeyMalformed)
+    }
+
+    /// Write binary DER-encoded PKCS#8 private key to the given path.
+    #[cfg(feature = "std")]
+    pub fn write_der_file(&self, path: impl AsRef<Path>) -> Result<()> {
+        fs::write(path, self.as_der()).map_err(|_| Error::KeyMalformed)
+    }
+
+    /// Encrypt this private key under the given password, producing a
+    /// PBES2/PBKDF2-HMAC-SHA256/AES-256-CBC encrypted PKCS#8 document.
+    ///
+    /// The salt and AES-256-CBC IV are drawn from `rng`, which must be a
+    /// cryptographically secure random number generator.
+    #[cfg(feature = "encryption")]
+    pub fn encrypt(
+        &self,
+        mut rng: impl CryptoRng + RngCore,
+        password: impl AsRef<[u8]>,
+    ) -> Result<EncryptedPrivateKeyDocument> {
+        private_key_info::encrypted::encrypt(
+            self.as_der(),
+            password,
+            &mut rng,
+            private_key_info::encrypted::PBKDF2_ITERATIONS,
+        )
+    }
+}
+
+impl TryFrom<&[u8]> for PrivateKeyDocument {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self> {
+        Self::from_der(bytes)
+    }
+}
+
+impl<'a> TryFrom<PrivateKeyInfo<'a>> for PrivateKeyDocument {
+    type Error = Error;
+
+    fn try_from(private_key_info: PrivateKeyInfo<'a>) -> Result<Self> {
+        use der::Encodable;
+        Ok(Self(private_key_info.to_vec()?))
+    }
+}
+
+impl fmt::Debug for PrivateKeyDocument {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("PrivateKeyDocument")
+            .field(&self.private_key_info())
+            .finish()
+    }
+}
+
+#[cfg(all(test, feature = "encryption"))]
+mod tests {
+    use super::PrivateKeyDocument;
+    use crate::PrivateKeyInfo;
+    use der::{asn1::ObjectIdentifier, Encodable};
+    use rand_core::{CryptoRng, Error, RngCore};
+
+    const PASSWORD: &[u8] = b"hunter2"; // Bad password; don't actually use outside tests!
+    const PRIVATE_KEY: &[u8] = &[1, 2, 3, 4, 5, 6, 7, 8];
+
+    /// A non-cryptographic, fixed-output RNG: good enough to exercise
+    /// `encrypt`'s salt/IV generation in a test without pulling in a real
+    /// CSPRNG as a dev-dependency.
+    struct TestRng(u8);
+
+    impl RngCore for TestRng {
+        fn next_u32(&mut self) -> u32 {
+            rand_core::impls::next_u32_via_fill(self)
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            rand_core::impls::next_u64_via_fill(self)
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            for byte in dest {
+                self.0 = self.0.wrapping_add(1);
+                *byte = self.0;
+            }
+        }
+
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> core::result::Result<(), Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+
+    impl CryptoRng for TestRng {}
+
+    #[test]
+    fn encrypt_decrypt_round_trip() {
+        let algorithm = crate::AlgorithmIdentifier {
+            oid: "1.3.101.112".parse::<ObjectIdentifier>().unwrap(),
+            parameters: None,
+        };
+        let der = PrivateKeyInfo::new(algorithm, PRIVATE_KEY).to_vec().unwrap();
+        let doc = PrivateKeyDocument::from_der(&der).unwrap();
+
+        let encrypted = doc.encrypt(TestRng(0), PASSWORD).unwrap();
+        let decrypted = encrypted.decrypt(PASSWORD).unwrap();
+        assert_eq!(decrypted.as_der(), doc.as_der());
+    }
+}
@@ -0,0 +1,107 @@
+//! SPKI public key document.
+
+use crate::{Error, Result, SubjectPublicKeyInfo};
+use alloc::vec::Vec;
+use core::{convert::TryFrom, fmt};
+use der::Decodable;
+
+#[cfg(feature = "pem")]
+use der::pem::{self, LineEnding};
+
+#[cfg(feature = "std")]
+use std::{fs, path::Path};
+
+/// SPKI `SubjectPublicKeyInfo` document.
+///
+/// This type provides heap-backed storage for [`SubjectPublicKeyInfo`] encoded as ASN.1 DER.
+#[derive(Clone)]
+pub struct PublicKeyDocument(Vec<u8>);
+
+impl PublicKeyDocument {
+    /// Parse the [`SubjectPublicKeyInfo`] contained in this [`PublicKeyDocument`].
+    pub fn spki(&self) -> SubjectPublicKeyInfo<'_> {
+        SubjectPublicKeyInfo::from_der(self.0.as_slice()).expect("malformed PublicKeyDocument")
+    }
+
+    /// Get the DER-encoded bytes of this document.
+    pub fn as_der(&self) -> &[u8] {
+        self.0.as_slice()
+    }
+
+    /// Parse [`PublicKeyDocument`] from ASN.1 DER-encoded bytes.
+    pub fn from_der(bytes: &[u8]) -> Result<Self> {
+        SubjectPublicKeyInfo::from_der(bytes)?;
+        Ok(Self(bytes.to_vec()))
+    }
+
+    /// Parse [`PublicKeyDocument`] from PEM-encoded SPKI.
+    #[cfg(feature = "pem")]
+    pub fn from_pem(s: &str) -> Result<Self> {
+        let (label, der_bytes) = pem::decode_vec(s.as_bytes())?;
+
+        if label != crate::pem::PUBLIC_KEY_TYPE_LABEL {
+            return Err(Error::KeyMalformed);
+        }
+
+        Self::from_der(&der_bytes)
+    }
+
+    /// Serialize this [`PublicKeyDocument`] as PEM-encoded SPKI using the given line ending.
+    #[cfg(feature = "pem")]
+    pub fn to_pem(&self, line_ending: LineEnding) -> Result<alloc::string::String> {
+        Ok(pem::encode_string(
+            crate::pem::PUBLIC_KEY_TYPE_LABEL,
+            line_ending,
+            self.as_der(),
+        )?)
+    }
+
+    /// Load [`PublicKeyDocument`] from an ASCII PEM-encoded file on the local filesystem.
+    #[cfg(feature = "pem")]
+    #[cfg(feature = "std")]
+    pub fn read_pem_file(path: impl AsRef<Path>) -> Result<Self> {
+        Self::from_pem(&fs::read_to_string(path).map_err(|_| Error::KeyMalformed)?)
+    }
+
+    /// Load [`PublicKeyDocument`] from a DER-encoded file on the local filesystem.
+    #[cfg(feature = "std")]
+    pub fn read_der_file(path: impl AsRef<Path>) -> Result<Self> {
+        Self::from_der(&fs::read(path).map_err(|_| Error::KeyMalformed)?)
+    }
+
+    /// Write ASCII PEM-encoded SPKI public key to the given path.
+    #[cfg(feature = "pem")]
+    #[cfg(feature = "std")]
+    pub fn write_pem_file(&self, path: impl AsRef<Path>, line_ending: LineEnding) -> Result<()> {
+        fs::write(path, self.to_pem(line_ending)?.as_bytes()).map_err(|_| Error::KeyMalformed)
+    }
+
+    /// Write binary DER-encoded SPKI public key to the given path.
+    #[cfg(feature = "std")]
+    pub fn write_der_file(&self, path: impl AsRef<Path>) -> Result<()> {
+        fs::write(path, self.as_der()).map_err(|_| Error::KeyMalformed)
+    }
+}
+
+impl TryFrom<&[u8]> for PublicKeyDocument {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self> {
+        Self::from_der(bytes)
+    }
+}
+
+impl<'a> TryFrom<SubjectPublicKeyInfo<'a>> for PublicKeyDocument {
+    type Error = Error;
+
+    fn try_from(spki: SubjectPublicKeyInfo<'a>) -> Result<Self> {
+        use der::Encodable;
+        Ok(Self(spki.to_vec()?))
+    }
+}
+
+impl fmt::Debug for PublicKeyDocument {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("PublicKeyDocument").field(&self.spki()).finish()
+    }
+}
@@ -0,0 +1,86 @@
+//! Error types.
+
+use core::fmt;
+
+/// Result type.
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// Error type.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+    /// ASN.1 DER-related errors.
+    Asn1(der::Error),
+
+    /// Cryptographic errors.
+    ///
+    /// This is primarily used for detecting and handling invalid keys.
+    Crypto,
+
+    /// Text decoding errors (e.g. UTF-8).
+    #[cfg(feature = "alloc")]
+    Decode,
+
+    /// Malformed cryptographic key contained in a PKCS#8 document.
+    ///
+    /// This is intended for use in cases where a key is well-formed w.r.t.
+    /// the DER encoding, but violates structural constraints which are
+    /// specific to the algorithm in question (e.g. incorrect length).
+    KeyMalformed,
+
+    /// PEM encoding errors.
+    #[cfg(feature = "pem")]
+    Pem(der::pem::Error),
+
+    /// Public key errors.
+    PublicKey,
+
+    /// Version errors.
+    ///
+    /// Returned when a PKCS#8 document contains a `version` field which is
+    /// not one of the known values (`v1` or `v2`).
+    Version,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Asn1(err) => write!(f, "ASN.1 DER error: {}", err),
+            Error::Crypto => write!(f, "cryptographic error"),
+            #[cfg(feature = "alloc")]
+            Error::Decode => write!(f, "text decoding error"),
+            Error::KeyMalformed => write!(f, "private key is malformed"),
+            #[cfg(feature = "pem")]
+            Error::Pem(err) => write!(f, "PEM error: {}", err),
+            Error::PublicKey => write!(f, "public key error"),
+            Error::Version => write!(f, "unknown/unsupported PKCS#8 version"),
+        }
+    }
+}
+
+impl From<der::Error> for Error {
+    fn from(err: der::Error) -> Error {
+        // `Version::decode` and the v1/v2-vs-public-key consistency check in
+        // `PrivateKeyInfo::decode` both report an invalid PKCS#8 `version`
+        // field as a `der::ErrorKind::Value` for `Tag::Integer` (via
+        // `Tag::Integer.value_error()`), since `Version` is the only
+        // `INTEGER`-tagged value this crate decodes. Recover that as the
+        // more specific `Error::Version` rather than the generic `Error::Asn1`.
+        match err.kind() {
+            der::ErrorKind::Value {
+                tag: der::Tag::Integer,
+            } => Error::Version,
+            _ => Error::Asn1(err),
+        }
+    }
+}
+
+#[cfg(feature = "pem")]
+impl From<der::pem::Error> for Error {
+    fn from(err: der::pem::Error) -> Error {
+        Error::Pem(err)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
@@ -1,6 +1,7 @@
 //! Pure Rust implementation of Public-Key Cryptography Standards (PKCS) #8:
 //!
-//! Private-Key Information Syntax Specification (as defined in [RFC 5208]).
+//! Private-Key Information Syntax Specification (as defined in [RFC 5208],
+//! with the `OneAsymmetricKey` additions from [RFC 5958]).
 //!
 //! # About
 //! This is a minimalistic library targeting `no_std` platforms and small code
@@ -8,7 +9,8 @@
 //! of a heap:
 //!
 //! - [`EncryptedPrivateKeyInfo`]: (with `pkcs5` feature) encrypted key.
-//! - [`PrivateKeyInfo`]: algorithm identifier and data representing a private key.
+//! - [`PrivateKeyInfo`]: algorithm identifier and data representing a private key,
+//!   optionally including its associated public key as described in [RFC 5958].
 //! - [`SubjectPublicKeyInfo`]: algorithm identifier and data representing a public key
 //!   (re-exported from the [`spki`] crate)
 //!
@@ -44,14 +46,31 @@
 //! keys encrypted with the following algorithms:
 //!
 //! - [PKCS#5v2 Password Based Encryption Scheme 2 (RFC 8018)]
-//!   - Key derivation function: PBKDF2 with HMAC-SHA256 as the PRF
-//!   - Symmetric encryption: AES-128-CBC or AES-256-CBC
+//!   - Key derivation function: PBKDF2 with HMAC-SHA256 as the PRF, or
+//!     scrypt. Note that the `scrypt` feature does not currently gate
+//!     scrypt support out of the build: the underlying `pkcs5` dependency
+//!     always pulls in its `scrypt` crate as part of PBES2 support, so
+//!     scrypt-KDF keys decrypt successfully through `encryption` alone.
+//!     The feature is kept as a marker for scrypt support and in case a
+//!     future `pkcs5` release splits the two apart.
+//!   - Symmetric encryption: AES-128-CBC or AES-256-CBC, DES-EDE3-CBC (3DES,
+//!     with the `3des` feature enabled), or single DES-CBC (with the
+//!     `des-insecure` feature enabled, for reading legacy keys only)
+//!
+//! The reverse direction is also supported: with `alloc` and `encryption`
+//! enabled, [`PrivateKeyDocument::encrypt`] produces a PBES2/PBKDF2-HMAC-
+//! SHA256/AES-256-CBC encrypted PKCS#8 document from a password and a
+//! [`rand_core::CryptoRng`].
+//!
+//! With the `zeroize` feature enabled, decrypted key material is wiped from
+//! memory on drop.
 //!
 //! # Minimum Supported Rust Version
 //!
 //! This crate requires **Rust 1.47** at a minimum.
 //!
 //! [RFC 5208]: https://tools.ietf.org/html/rfc5208
+//! [RFC 5958]: https://tools.ietf.org/html/rfc5958
 //! [PKCS#5v2 Password Based Encryption Scheme 2 (RFC 8018)]: https://tools.ietf.org/html/rfc8018#section-6.2
 
 #![no_std]
@@ -73,6 +92,7 @@ extern crate std;
 mod error;
 mod private_key_info;
 mod traits;
+mod version;
 
 #[cfg(feature = "alloc")]
 mod document;
@@ -84,8 +104,9 @@ pub use crate::{
     error::{Error, Result},
     private_key_info::PrivateKeyInfo,
     traits::{FromPrivateKey, FromPublicKey},
+    version::Version,
 };
-pub use der::{self, ObjectIdentifier};
+pub use der::{self, asn1::ObjectIdentifier};
 pub use spki::{AlgorithmIdentifier, SubjectPublicKeyInfo};
 
 #[cfg(feature = "alloc")]
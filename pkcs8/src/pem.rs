@@ -0,0 +1,11 @@
+//! PEM encoding/decoding support.
+
+/// Type label for PKCS#8 private keys.
+pub(crate) const PRIVATE_KEY_TYPE_LABEL: &str = "PRIVATE KEY";
+
+/// Type label for PKCS#8 encrypted private keys.
+#[cfg(feature = "pkcs5")]
+pub(crate) const ENCRYPTED_PRIVATE_KEY_TYPE_LABEL: &str = "ENCRYPTED PRIVATE KEY";
+
+/// Type label for SPKI public keys.
+pub(crate) const PUBLIC_KEY_TYPE_LABEL: &str = "PUBLIC KEY";
@@ -0,0 +1,282 @@
+//! PKCS#8 `PrivateKeyInfo`.
+
+use crate::{AlgorithmIdentifier, Error, Result, Version};
+use core::{convert::TryFrom, fmt};
+use der::{
+    asn1::{BitString, ContextSpecific, OctetString},
+    Decodable, Decoder, Encodable, Sequence, Tag, TagNumber,
+};
+
+#[cfg(feature = "alloc")]
+use crate::document::private_key::PrivateKeyDocument;
+
+#[cfg(feature = "pkcs5")]
+pub(crate) mod encrypted;
+
+/// Context-specific tag number for the `publicKey` field.
+const PUBLIC_KEY_TAG: TagNumber = TagNumber::new(1);
+
+/// PKCS#8 `PrivateKeyInfo`.
+///
+/// ASN.1 structure containing an algorithm identifier and private key
+/// data, optionally accompanied by the corresponding public key.
+///
+/// Supports the `OneAsymmetricKey` structure as defined in [RFC 5958 Section 2],
+/// which is a superset of the `PrivateKeyInfo` structure from [RFC 5208 Section 5]:
+///
+/// ```text
+/// OneAsymmetricKey ::= SEQUENCE {
+///     version                   Version,
+///     privateKeyAlgorithm       PrivateKeyAlgorithmIdentifier,
+///     privateKey                PrivateKey,
+///     attributes            [0] Attributes OPTIONAL,
+///     ...,
+///     [[2: publicKey       [1] PublicKey OPTIONAL ]],
+///     ...
+///   }
+///
+/// Version ::= INTEGER { v1(0), v2(1) } (v1, ..., v2)
+///
+/// PrivateKeyAlgorithmIdentifier ::= AlgorithmIdentifier
+///
+/// PrivateKey ::= OCTET STRING
+///
+/// PublicKey ::= BIT STRING
+/// ```
+///
+/// When `public_key` is `None` this is encoded/decoded as `v1`. When it is
+/// `Some`, the `[1] IMPLICIT` `publicKey` field is present and the version
+/// is encoded/decoded as `v2`, per [RFC 5958 Section 2].
+///
+/// The `attributes` field is not presently supported and, when encountered
+/// while decoding, is ignored.
+///
+/// [RFC 5958 Section 2]: https://datatracker.ietf.org/doc/html/rfc5958#section-2
+/// [RFC 5208 Section 5]: https://datatracker.ietf.org/doc/html/rfc5208#section-5
+#[derive(Clone)]
+pub struct PrivateKeyInfo<'a> {
+    /// X.509 `AlgorithmIdentifier` for the private key type.
+    pub algorithm: AlgorithmIdentifier<'a>,
+
+    /// Private key data.
+    pub private_key: &'a [u8],
+
+    /// Public key data, optionally available if `version` is V2.
+    pub public_key: Option<&'a [u8]>,
+}
+
+impl<'a> PrivateKeyInfo<'a> {
+    /// Create a new [`PrivateKeyInfo`] without a public key.
+    pub fn new(algorithm: AlgorithmIdentifier<'a>, private_key: &'a [u8]) -> Self {
+        Self {
+            algorithm,
+            private_key,
+            public_key: None,
+        }
+    }
+
+    /// Get the PKCS#8 [`Version`] for this key.
+    ///
+    /// Returns [`Version::V2`] if a public key is present, [`Version::V1`] otherwise.
+    pub fn version(&self) -> Version {
+        if self.public_key.is_some() {
+            Version::V2
+        } else {
+            Version::V1
+        }
+    }
+
+    /// Return a new [`PrivateKeyInfo`] with the given public key attached.
+    #[must_use]
+    pub fn with_public_key(mut self, public_key: &'a [u8]) -> Self {
+        self.public_key = Some(public_key);
+        self
+    }
+
+    /// Encode this [`PrivateKeyInfo`] as ASN.1 DER, storing the result in a
+    /// heap-backed [`PrivateKeyDocument`].
+    #[cfg(feature = "alloc")]
+    pub fn to_pkcs8_der(&self) -> Result<PrivateKeyDocument> {
+        use core::convert::TryInto;
+        self.clone().try_into()
+    }
+}
+
+impl<'a> Decodable<'a> for PrivateKeyInfo<'a> {
+    fn decode(decoder: &mut Decoder<'a>) -> der::Result<Self> {
+        decoder.sequence(|decoder| {
+            let version = Version::decode(decoder)?;
+            let algorithm = decoder.decode()?;
+            let private_key = decoder.octet_string()?.as_bytes();
+
+            // The `[0] IMPLICIT Attributes` field (not currently modeled) is
+            // skipped automatically: `context_specific` below consumes and
+            // discards any lower-numbered context-specific field before
+            // looking for `PUBLIC_KEY_TAG`.
+            let public_key = decoder
+                .context_specific::<BitString<'_>>(PUBLIC_KEY_TAG, der::TagMode::Implicit)?
+                .and_then(|bs| bs.as_bytes());
+
+            if version.is_v2() != public_key.is_some() {
+                // `version` must be `v2` iff a `publicKey` field is present.
+                return Err(Tag::Integer.value_error());
+            }
+
+            Ok(Self {
+                algorithm,
+                private_key,
+                public_key,
+            })
+        })
+    }
+}
+
+impl<'a> Sequence<'a> for PrivateKeyInfo<'a> {
+    fn fields<F, T>(&self, f: F) -> der::Result<T>
+    where
+        F: FnOnce(&[&dyn Encodable]) -> der::Result<T>,
+    {
+        let version = self.version();
+        let private_key = OctetString::new(self.private_key)?;
+
+        match &self.public_key {
+            Some(public_key) => {
+                let public_key = ContextSpecific {
+                    tag_number: PUBLIC_KEY_TAG,
+                    tag_mode: der::TagMode::Implicit,
+                    value: BitString::new(0, public_key)?,
+                };
+
+                f(&[&version, &self.algorithm, &private_key, &public_key])
+            }
+            None => f(&[&version, &self.algorithm, &private_key]),
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for PrivateKeyInfo<'a> {
+    type Error = Error;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self> {
+        Ok(Self::from_der(bytes)?)
+    }
+}
+
+impl<'a> fmt::Debug for PrivateKeyInfo<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PrivateKeyInfo")
+            .field("algorithm", &self.algorithm)
+            .field("public_key", &self.public_key)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::{PrivateKeyInfo, PUBLIC_KEY_TAG};
+    use crate::{AlgorithmIdentifier, Error};
+    use alloc::vec::Vec;
+    use core::convert::TryFrom;
+    use der::{
+        asn1::{BitString, ContextSpecific, ObjectIdentifier, OctetString},
+        Decodable, Encodable, TagMode,
+    };
+
+    const PRIVATE_KEY: &[u8] = &[1, 2, 3, 4, 5, 6, 7, 8];
+    const PUBLIC_KEY: &[u8] = &[10, 20, 30, 40, 50];
+
+    fn algorithm() -> AlgorithmIdentifier<'static> {
+        AlgorithmIdentifier {
+            oid: "1.3.101.112".parse::<ObjectIdentifier>().unwrap(),
+            parameters: None,
+        }
+    }
+
+    /// Wrap `content` in a `SEQUENCE` header (`content` must be under 128 bytes).
+    fn der_sequence(content: &[u8]) -> Vec<u8> {
+        assert!(content.len() < 0x80);
+        let mut der = alloc::vec![0x30, content.len() as u8];
+        der.extend_from_slice(content);
+        der
+    }
+
+    #[test]
+    fn v1_round_trip() {
+        let key = PrivateKeyInfo::new(algorithm(), PRIVATE_KEY);
+        let der = key.to_vec().unwrap();
+
+        let key2 = PrivateKeyInfo::from_der(&der).unwrap();
+        assert_eq!(key2.version(), crate::Version::V1);
+        assert_eq!(key2.algorithm, algorithm());
+        assert_eq!(key2.private_key, PRIVATE_KEY);
+        assert_eq!(key2.public_key, None);
+    }
+
+    #[test]
+    fn v2_with_public_key_round_trip() {
+        let key = PrivateKeyInfo::new(algorithm(), PRIVATE_KEY).with_public_key(PUBLIC_KEY);
+        let der = key.to_vec().unwrap();
+
+        let key2 = PrivateKeyInfo::from_der(&der).unwrap();
+        assert_eq!(key2.version(), crate::Version::V2);
+        assert_eq!(key2.private_key, PRIVATE_KEY);
+        assert_eq!(key2.public_key, Some(PUBLIC_KEY));
+    }
+
+    #[test]
+    fn tolerates_attributes_before_public_key() {
+        // A dummy `[0] IMPLICIT Attributes` field, which this crate doesn't
+        // model but must skip over to reach the `[1]` `publicKey` field.
+        let attributes: &[u8] = &[0xa0, 0x02, 0xaa, 0xbb];
+
+        let public_key = ContextSpecific {
+            tag_number: PUBLIC_KEY_TAG,
+            tag_mode: TagMode::Implicit,
+            value: BitString::new(0, PUBLIC_KEY).unwrap(),
+        };
+
+        let mut content = crate::Version::V2.to_vec().unwrap();
+        content.extend_from_slice(&algorithm().to_vec().unwrap());
+        content.extend_from_slice(&OctetString::new(PRIVATE_KEY).unwrap().to_vec().unwrap());
+        content.extend_from_slice(attributes);
+        content.extend_from_slice(&public_key.to_vec().unwrap());
+
+        let der = der_sequence(&content);
+        let key = PrivateKeyInfo::from_der(&der).unwrap();
+        assert_eq!(key.private_key, PRIVATE_KEY);
+        assert_eq!(key.public_key, Some(PUBLIC_KEY));
+    }
+
+    #[test]
+    fn rejects_v1_with_public_key() {
+        let public_key = ContextSpecific {
+            tag_number: PUBLIC_KEY_TAG,
+            tag_mode: TagMode::Implicit,
+            value: BitString::new(0, PUBLIC_KEY).unwrap(),
+        };
+
+        let mut content = crate::Version::V1.to_vec().unwrap();
+        content.extend_from_slice(&algorithm().to_vec().unwrap());
+        content.extend_from_slice(&OctetString::new(PRIVATE_KEY).unwrap().to_vec().unwrap());
+        content.extend_from_slice(&public_key.to_vec().unwrap());
+
+        let der = der_sequence(&content);
+        assert!(matches!(
+            PrivateKeyInfo::try_from(der.as_slice()),
+            Err(Error::Version)
+        ));
+    }
+
+    #[test]
+    fn rejects_v2_without_public_key() {
+        let mut content = crate::Version::V2.to_vec().unwrap();
+        content.extend_from_slice(&algorithm().to_vec().unwrap());
+        content.extend_from_slice(&OctetString::new(PRIVATE_KEY).unwrap().to_vec().unwrap());
+
+        let der = der_sequence(&content);
+        assert!(matches!(
+            PrivateKeyInfo::try_from(der.as_slice()),
+            Err(Error::Version)
+        ));
+    }
+}
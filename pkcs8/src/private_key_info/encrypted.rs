@@ -0,0 +1,267 @@
+//! PKCS#8 `EncryptedPrivateKeyInfo`.
+
+use core::fmt;
+use der::{asn1::OctetString, Decodable, Decoder, Encodable, Sequence};
+use pkcs5::EncryptionScheme;
+
+#[cfg(feature = "encryption")]
+use crate::{document::private_key::PrivateKeyDocument, Error, PrivateKeyInfo, Result};
+
+#[cfg(all(feature = "alloc", feature = "encryption"))]
+use {
+    crate::document::encrypted_private_key::EncryptedPrivateKeyDocument,
+    core::convert::TryFrom,
+    pkcs5::pbes2,
+    rand_core::{CryptoRng, RngCore},
+};
+
+/// Default PBKDF2 iteration count used by [`encrypt`].
+#[cfg(all(feature = "alloc", feature = "encryption"))]
+pub(crate) const PBKDF2_ITERATIONS: u32 = 10_000;
+
+/// AES block size in bytes.
+///
+/// Used both as the PBES2 IV size and as the amount of extra buffer
+/// capacity reserved for PKCS#7 padding when encrypting in-place.
+#[cfg(all(feature = "alloc", feature = "encryption"))]
+const IV_SIZE: usize = 16;
+
+/// Salt size in bytes used when deriving the PBES2 encryption key.
+#[cfg(all(feature = "alloc", feature = "encryption"))]
+const SALT_SIZE: usize = 16;
+
+/// PKCS#8 `EncryptedPrivateKeyInfo`.
+///
+/// ASN.1 structure containing a PKCS#5 [`EncryptionScheme`] identifier for a
+/// particular password-based encryption scheme along with a private key
+/// which has been encrypted under that scheme.
+///
+/// ```text
+/// EncryptedPrivateKeyInfo ::= SEQUENCE {
+///   encryptionAlgorithm  EncryptionAlgorithmIdentifier,
+///   encryptedData        EncryptedData }
+///
+/// EncryptionAlgorithmIdentifier ::= AlgorithmIdentifier
+///
+/// EncryptedData ::= OCTET STRING
+/// ```
+#[derive(Clone)]
+pub struct EncryptedPrivateKeyInfo<'a> {
+    /// Algorithm identifier describing a password-based symmetric encryption scheme.
+    pub encryption_algorithm: EncryptionScheme<'a>,
+
+    /// Encrypted data.
+    pub encrypted_data: &'a [u8],
+}
+
+impl<'a> EncryptedPrivateKeyInfo<'a> {
+    /// Attempt to decrypt this encrypted private key using the given password
+    /// to derive an encryption key.
+    ///
+    /// Supports PBES2 with either PBKDF2-HMAC-SHA256 or (with the `scrypt`
+    /// feature enabled) scrypt as the key derivation function, and
+    /// AES-128-CBC/AES-256-CBC, DES-EDE3-CBC (with the `3des` feature), or
+    /// single DES-CBC (with the `des-insecure` feature — present only for
+    /// reading legacy keys, never use it to produce new ones) as the
+    /// symmetric cipher. The KDF and cipher are both selected automatically
+    /// based on the `AlgorithmIdentifier`s embedded in the PBES2 parameters.
+    ///
+    /// With the `zeroize` feature enabled, the intermediate decryption
+    /// buffer is wiped on drop, so the plaintext private key only persists
+    /// in the returned [`PrivateKeyDocument`].
+    ///
+    /// There is no password or MAC equality check for this crate to make
+    /// constant-time: PBES2 as implemented by the `pkcs5` dependency is
+    /// CBC encryption with PKCS#7 padding and no MAC step at all, so the
+    /// only built-in integrity check is whether the padding happens to be
+    /// well-formed after decryption, which `pkcs5` reports as a plain
+    /// [`Error::Crypto`] here. A `subtle`-gated constant-time comparison
+    /// has no equality check to attach to in this scheme.
+    #[cfg(feature = "encryption")]
+    pub fn decrypt(&self, password: impl AsRef<[u8]>) -> Result<PrivateKeyDocument> {
+        let pbes2 = match &self.encryption_algorithm {
+            EncryptionScheme::Pbes2(params) => params,
+            _ => return Err(Error::Crypto),
+        };
+
+        #[cfg(feature = "zeroize")]
+        let mut buffer = zeroize::Zeroizing::new(self.encrypted_data.to_vec());
+        #[cfg(not(feature = "zeroize"))]
+        let mut buffer = self.encrypted_data.to_vec();
+
+        // `Pbes2Params::decrypt_in_place` dispatches on the embedded KDF
+        // (PBKDF2 or, with the `scrypt` feature, scrypt) and cipher (AES,
+        // or with the `3des`/`des-insecure` features, DES-EDE3/DES) internally.
+        let plaintext = pbes2
+            .decrypt_in_place(password, &mut buffer[..])
+            .map_err(|_| Error::Crypto)?;
+
+        let _ = PrivateKeyInfo::try_from(plaintext)?;
+        PrivateKeyDocument::from_der(plaintext)
+    }
+}
+
+/// Encrypt the DER encoding of a [`PrivateKeyInfo`] with a symmetric
+/// encryption key derived from `password` using PBKDF2-HMAC-SHA256, and
+/// assemble the result as an [`EncryptedPrivateKeyDocument`].
+///
+/// A random salt and AES-256-CBC IV are drawn from the provided
+/// [`CryptoRng`].
+#[cfg(all(feature = "alloc", feature = "encryption"))]
+pub(crate) fn encrypt(
+    pkcs8_der: &[u8],
+    password: impl AsRef<[u8]>,
+    rng: &mut (impl CryptoRng + RngCore),
+    pbkdf2_iterations: u32,
+) -> Result<EncryptedPrivateKeyDocument> {
+    let mut salt = [0u8; SALT_SIZE];
+    rng.fill_bytes(&mut salt);
+
+    let mut iv = [0u8; IV_SIZE];
+    rng.fill_bytes(&mut iv);
+
+    let pbes2_params = pbes2::Parameters::pbkdf2_sha256_aes256cbc(pbkdf2_iterations, &salt, &iv)
+        .map_err(|_| Error::Crypto)?;
+
+    // `encrypt_in_place` writes the CBC-padded ciphertext over `buffer`,
+    // which must have room for up to one extra block of PKCS#7 padding
+    // beyond `pkcs8_der`'s length (passed as `pos`).
+    let mut buffer = pkcs8_der.to_vec();
+    buffer.extend_from_slice(&[0u8; IV_SIZE]);
+    let encrypted_data = pbes2_params
+        .encrypt_in_place(password, &mut buffer, pkcs8_der.len())
+        .map_err(|_| Error::Crypto)?;
+
+    EncryptedPrivateKeyDocument::try_from(EncryptedPrivateKeyInfo {
+        encryption_algorithm: pbes2_params.into(),
+        encrypted_data,
+    })
+}
+
+impl<'a> Decodable<'a> for EncryptedPrivateKeyInfo<'a> {
+    fn decode(decoder: &mut Decoder<'a>) -> der::Result<Self> {
+        decoder.sequence(|decoder| {
+            let encryption_algorithm = decoder.decode()?;
+            let encrypted_data = decoder.octet_string()?.as_bytes();
+
+            Ok(Self {
+                encryption_algorithm,
+                encrypted_data,
+            })
+        })
+    }
+}
+
+impl<'a> Sequence<'a> for EncryptedPrivateKeyInfo<'a> {
+    fn fields<F, T>(&self, f: F) -> der::Result<T>
+    where
+        F: FnOnce(&[&dyn Encodable]) -> der::Result<T>,
+    {
+        let encrypted_data = OctetString::new(self.encrypted_data)?;
+        f(&[&self.encryption_algorithm, &encrypted_data])
+    }
+}
+
+impl<'a> fmt::Debug for EncryptedPrivateKeyInfo<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EncryptedPrivateKeyInfo")
+            .field("encryption_algorithm", &self.encryption_algorithm)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(all(test, feature = "encryption"))]
+mod tests {
+    use super::EncryptedPrivateKeyInfo;
+    use crate::{AlgorithmIdentifier, PrivateKeyInfo};
+    use alloc::vec::Vec;
+    use der::{asn1::ObjectIdentifier, Encodable};
+    use pkcs5::pbes2;
+
+    #[cfg(feature = "des-insecure")]
+    use der::Decodable;
+
+    const PASSWORD: &[u8] = b"hunter2"; // Bad password; don't actually use outside tests!
+    const PRIVATE_KEY: &[u8] = &[1, 2, 3, 4, 5, 6, 7, 8];
+
+    fn sample_pkcs8_der() -> Vec<u8> {
+        let algorithm = AlgorithmIdentifier {
+            oid: "1.3.101.112".parse::<ObjectIdentifier>().unwrap(),
+            parameters: None,
+        };
+        PrivateKeyInfo::new(algorithm, PRIVATE_KEY).to_vec().unwrap()
+    }
+
+    /// Encrypt `der` under `params`, then decrypt it back through
+    /// [`EncryptedPrivateKeyInfo::decrypt`] and check it round-trips.
+    fn assert_decrypts(params: pbes2::Parameters<'_>, der: &[u8]) {
+        let encrypted_data = params.encrypt(PASSWORD, der).unwrap();
+
+        let key_info = EncryptedPrivateKeyInfo {
+            encryption_algorithm: params.into(),
+            encrypted_data: &encrypted_data,
+        };
+
+        let decrypted = key_info.decrypt(PASSWORD).unwrap();
+        assert_eq!(decrypted.as_der(), der);
+    }
+
+    #[test]
+    fn decrypts_pbkdf2_aes256cbc() {
+        let der = sample_pkcs8_der();
+        let params =
+            pbes2::Parameters::pbkdf2_sha256_aes256cbc(10_000, b"saltsalt", &[0x42; 16]).unwrap();
+        assert_decrypts(params, &der);
+    }
+
+    #[test]
+    #[cfg(feature = "scrypt")]
+    fn decrypts_scrypt_aes256cbc() {
+        let der = sample_pkcs8_der();
+        let params =
+            pbes2::Parameters::scrypt_aes256cbc(Default::default(), b"saltsalt", &[0x42; 16])
+                .unwrap();
+        assert_decrypts(params, &der);
+    }
+
+    #[test]
+    #[cfg(feature = "3des")]
+    fn decrypts_des_ede3_cbc() {
+        let der = sample_pkcs8_der();
+        let kdf = pbes2::Pbkdf2Params::hmac_with_sha256(10_000, b"saltsalt")
+            .unwrap()
+            .into();
+        let params = pbes2::Parameters {
+            kdf,
+            encryption: pbes2::EncryptionScheme::DesEde3Cbc { iv: &[0x24; 8] },
+        };
+        assert_decrypts(params, &der);
+    }
+
+    // `pkcs5` refuses to *produce* new DES-CBC ciphertext (it's only meant
+    // for reading legacy keys), so unlike the other schemes above this is
+    // tested against a fixture produced by OpenSSL rather than a round trip:
+    //
+    //   openssl genpkey -algorithm ED25519 -out key.pem
+    //   openssl pkcs8 -topk8 -v2 des-cbc -v2prf hmacWithSHA256 \
+    //       -in key.pem -outform DER -out enc.der -passout pass:hunter2
+    #[test]
+    #[cfg(feature = "des-insecure")]
+    fn decrypts_des_cbc_openssl_fixture() {
+        let encrypted_der = hex_literal::hex!(
+            "30818f305306092a864886f70d01050d3046303106092a864886f70d01050c"
+            "302404101cf47bd7be31de62018c5cc4186416b202020800300c06082a8648"
+            "86f70d02090500301106052b0e03020704087c38ab3cbf2ee78804385bdf5b"
+            "19f0bc326dec99258d375050107ce0e76c9592c13976d74470836a000b5903"
+            "cdde7a3fb706328c50741f334876c450299349daed94"
+        );
+        let expected_der = hex_literal::hex!(
+            "302e020100300506032b657004220420c3be2832ec542d5a28e23b0c15b326"
+            "75f31c5de4a147eb424c8c6978075cb30c"
+        );
+
+        let key_info = EncryptedPrivateKeyInfo::from_der(&encrypted_der).unwrap();
+        let decrypted = key_info.decrypt(PASSWORD).unwrap();
+        assert_eq!(decrypted.as_der(), expected_der);
+    }
+}
@@ -0,0 +1,102 @@
+//! PKCS#8 `Version` (see [RFC 5958 Section 2]).
+//!
+//! [RFC 5958 Section 2]: https://datatracker.ietf.org/doc/html/rfc5958#section-2
+
+use crate::{Error, Result};
+use core::convert::TryFrom;
+use der::{Decodable, Decoder, Encodable, Encoder, Length, Tag};
+
+/// Version identifier for PKCS#8 private keys.
+///
+/// ```text
+/// Version ::= INTEGER { v1(0), v2(1) } (v1, ..., v2)
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Version {
+    /// Denotes PKCS#8 v1: no public key field.
+    V1 = 0,
+
+    /// Denotes PKCS#8 v2 as described in RFC 5958: includes a public key field.
+    V2 = 1,
+}
+
+impl Version {
+    /// Is this version 1?
+    pub fn is_v1(self) -> bool {
+        self == Version::V1
+    }
+
+    /// Is this version 2?
+    pub fn is_v2(self) -> bool {
+        self == Version::V2
+    }
+}
+
+impl TryFrom<u8> for Version {
+    type Error = Error;
+
+    fn try_from(byte: u8) -> Result<Version> {
+        match byte {
+            0 => Ok(Version::V1),
+            1 => Ok(Version::V2),
+            _ => Err(Error::Version),
+        }
+    }
+}
+
+impl From<Version> for u8 {
+    fn from(version: Version) -> u8 {
+        version as u8
+    }
+}
+
+impl<'a> Decodable<'a> for Version {
+    fn decode(decoder: &mut Decoder<'a>) -> der::Result<Self> {
+        let byte = u8::decode(decoder)?;
+        Version::try_from(byte).map_err(|_| Tag::Integer.value_error())
+    }
+}
+
+impl Encodable for Version {
+    fn encoded_len(&self) -> der::Result<Length> {
+        u8::from(*self).encoded_len()
+    }
+
+    fn encode(&self, encoder: &mut Encoder<'_>) -> der::Result<()> {
+        u8::from(*self).encode(encoder)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Version;
+    use core::convert::TryFrom;
+    use der::Decodable;
+
+    #[test]
+    fn try_from_known_values() {
+        assert_eq!(Version::try_from(0).unwrap(), Version::V1);
+        assert_eq!(Version::try_from(1).unwrap(), Version::V2);
+    }
+
+    #[test]
+    fn try_from_rejects_unknown_values() {
+        assert!(Version::try_from(2).is_err());
+        assert!(Version::try_from(255).is_err());
+    }
+
+    #[test]
+    fn is_v1_is_v2() {
+        assert!(Version::V1.is_v1());
+        assert!(!Version::V1.is_v2());
+        assert!(Version::V2.is_v2());
+        assert!(!Version::V2.is_v1());
+    }
+
+    #[test]
+    fn decode_rejects_unknown_values() {
+        // INTEGER { 2 }
+        let der = [0x02, 0x01, 0x02];
+        assert!(Version::from_der(&der).is_err());
+    }
+}